@@ -0,0 +1,83 @@
+use crate::vec2::Vec2;
+
+/// Types that can be packed into a vertex/uniform buffer without per-field
+/// copying, relying on `Vec2`'s `#[repr(C)]` layout guarantee.
+pub trait Bytes {
+    /// Writes the raw bytes of `self` into `buffer`, which must be at least
+    /// `byte_len()` bytes long.
+    fn write_bytes(&self, buffer: &mut [u8]);
+
+    /// The number of bytes `as_bytes`/`write_bytes` will produce.
+    fn byte_len(&self) -> usize;
+
+    /// Borrows `self` as its raw byte representation.
+    fn as_bytes(&self) -> &[u8];
+}
+
+impl Bytes for Vec2 {
+    fn write_bytes(&self, buffer: &mut [u8]) {
+        buffer[..self.byte_len()].copy_from_slice(self.as_bytes());
+    }
+
+    fn byte_len(&self) -> usize {
+        std::mem::size_of::<Vec2>()
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        // Safe because Vec2 is #[repr(C)] and made up entirely of f32 fields.
+        unsafe {
+            std::slice::from_raw_parts(self as *const Vec2 as *const u8, self.byte_len())
+        }
+    }
+}
+
+impl Bytes for [Vec2] {
+    fn write_bytes(&self, buffer: &mut [u8]) {
+        buffer[..self.byte_len()].copy_from_slice(self.as_bytes());
+    }
+
+    fn byte_len(&self) -> usize {
+        std::mem::size_of_val(self)
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        // Safe because Vec2 is #[repr(C)] and made up entirely of f32 fields.
+        unsafe {
+            std::slice::from_raw_parts(self.as_ptr() as *const u8, self.byte_len())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_len() {
+        let vec = Vec2::new(1.0, 2.0);
+        assert_eq!(vec.byte_len(), 8);
+    }
+
+    #[test]
+    fn test_round_trip_single() {
+        let vec = Vec2::new(1.0, 2.0);
+        let mut buffer = [0u8; 8];
+        vec.write_bytes(&mut buffer);
+        let x = f32::from_ne_bytes(buffer[0..4].try_into().unwrap());
+        let y = f32::from_ne_bytes(buffer[4..8].try_into().unwrap());
+        assert_eq!(Vec2::new(x, y), vec);
+    }
+
+    #[test]
+    fn test_round_trip_slice() {
+        let vecs = [Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)];
+        let bytes = vecs.as_bytes();
+        assert_eq!(bytes.len(), 16);
+        let x0 = f32::from_ne_bytes(bytes[0..4].try_into().unwrap());
+        let y0 = f32::from_ne_bytes(bytes[4..8].try_into().unwrap());
+        let x1 = f32::from_ne_bytes(bytes[8..12].try_into().unwrap());
+        let y1 = f32::from_ne_bytes(bytes[12..16].try_into().unwrap());
+        assert_eq!(Vec2::new(x0, y0), vecs[0]);
+        assert_eq!(Vec2::new(x1, y1), vecs[1]);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod bytes;
+pub mod transform;
+pub mod vec2;
+
+pub use bytes::Bytes;
+pub use transform::Transform2D;
+pub use vec2::{IVec2, UVec2, Vec2, Vector2};
@@ -0,0 +1,145 @@
+use crate::vec2::Vec2;
+
+/// A 2D affine transform, storing translation, rotation, and non-uniform scale
+/// as the six non-trivial entries of a 3x3 homogeneous matrix.
+///
+/// A point `(x, y)` maps to `(a*x + c*y + tx, b*x + d*y + ty)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform2D {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub tx: f32,
+    pub ty: f32,
+}
+
+impl Transform2D {
+    pub fn identity() -> Self {
+        Self {
+            a: 1.0,
+            b: 0.0,
+            c: 0.0,
+            d: 1.0,
+            tx: 0.0,
+            ty: 0.0,
+        }
+    }
+
+    pub fn translation(offset: Vec2) -> Self {
+        Self {
+            a: 1.0,
+            b: 0.0,
+            c: 0.0,
+            d: 1.0,
+            tx: offset.x,
+            ty: offset.y,
+        }
+    }
+
+    pub fn rotation(rad: f32) -> Self {
+        let (sin, cos) = rad.sin_cos();
+        Self {
+            a: cos,
+            b: sin,
+            c: -sin,
+            d: cos,
+            tx: 0.0,
+            ty: 0.0,
+        }
+    }
+
+    pub fn scale(sx: f32, sy: f32) -> Self {
+        Self {
+            a: sx,
+            b: 0.0,
+            c: 0.0,
+            d: sy,
+            tx: 0.0,
+            ty: 0.0,
+        }
+    }
+
+    /// Composes `self` followed by `other`, equivalent to multiplying the
+    /// underlying homogeneous matrices as `other * self`.
+    pub fn then(&self, other: &Transform2D) -> Transform2D {
+        Transform2D {
+            a: other.a * self.a + other.c * self.b,
+            b: other.b * self.a + other.d * self.b,
+            c: other.a * self.c + other.c * self.d,
+            d: other.b * self.c + other.d * self.d,
+            tx: other.a * self.tx + other.c * self.ty + other.tx,
+            ty: other.b * self.tx + other.d * self.ty + other.ty,
+        }
+    }
+
+    /// Applies the full affine transform, including translation.
+    pub fn transform_point(&self, point: Vec2) -> Vec2 {
+        Vec2::new(
+            self.a * point.x + self.c * point.y + self.tx,
+            self.b * point.x + self.d * point.y + self.ty,
+        )
+    }
+
+    /// Applies only the linear part of the transform, ignoring translation.
+    pub fn transform_vector(&self, vector: Vec2) -> Vec2 {
+        Vec2::new(
+            self.a * vector.x + self.c * vector.y,
+            self.b * vector.x + self.d * vector.y,
+        )
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity_leaves_point_unchanged() {
+        let point = Vec2::new(3.0, 4.0);
+        assert_eq!(Transform2D::identity().transform_point(point), point);
+    }
+
+    #[test]
+    fn test_translation() {
+        let t = Transform2D::translation(Vec2::new(1.0, 2.0));
+        assert_eq!(t.transform_point(Vec2::new(3.0, 4.0)), Vec2::new(4.0, 6.0));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let t = Transform2D::rotation(std::f32::consts::FRAC_PI_2);
+        let result = t.transform_point(Vec2::new(1.0, 0.0));
+        assert!((result.x - 0.0).abs() < 1e-6);
+        assert!((result.y - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_scale() {
+        let t = Transform2D::scale(2.0, 3.0);
+        assert_eq!(t.transform_point(Vec2::new(1.0, 1.0)), Vec2::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn test_transform_vector_ignores_translation() {
+        let t = Transform2D::translation(Vec2::new(5.0, 5.0));
+        assert_eq!(t.transform_vector(Vec2::new(1.0, 1.0)), Vec2::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_then_composes_in_order() {
+        let translate = Transform2D::translation(Vec2::new(1.0, 0.0));
+        let rotate = Transform2D::rotation(std::f32::consts::FRAC_PI_2);
+        let combined = translate.then(&rotate);
+        let result = combined.transform_point(Vec2::new(0.0, 0.0));
+        let expected = rotate.transform_point(translate.transform_point(Vec2::new(0.0, 0.0)));
+        assert!((result.x - expected.x).abs() < 1e-6);
+        assert!((result.y - expected.y).abs() < 1e-6);
+    }
+}
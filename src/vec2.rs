@@ -1,47 +1,127 @@
-use num_traits::Zero;
+use num_traits::{Float, NumCast, ToPrimitive};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+#[repr(C)]
 #[derive(Clone, Copy, Debug, Default)]
-pub struct Vec2 {
-	pub x: f32,
-    pub y: f32
+pub struct Vector2<T> {
+	pub x: T,
+    pub y: T
 }
 
-impl Vec2 {
-    pub fn new(x: f32, y: f32) -> Self {
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for Vector2<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.x, &self.y).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vector2<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (x, y) = <(T, T)>::deserialize(deserializer)?;
+        Ok(Vector2 { x, y })
+    }
+}
+
+/// Floating-point 2D vector, the original shape of this type.
+pub type Vec2 = Vector2<f32>;
+/// Signed integer 2D vector, for grid/tile coordinates.
+pub type IVec2 = Vector2<i32>;
+/// Unsigned integer 2D vector, for pixel/texel coordinates.
+///
+/// Subtraction-based operations (`Sub`, `cross`, `sub`) panic on overflow in
+/// debug builds whenever the result would be negative, which is routine when
+/// differencing two arbitrary points. Convert through `cast::<i32>()` first
+/// if the operands aren't known to be ordered.
+pub type UVec2 = Vector2<u32>;
+
+impl<T> Vector2<T> {
+    pub fn new(x: T, y: T) -> Self {
         Self {x, y}
     }
 
-	pub fn add<U: Into<Vec2>>(&mut self, rhs: U) -> &mut Self {
-		let rhs: Vec2 = rhs.into();
+    /// Applies `f` to both components, producing a vector over the result type.
+    pub fn map<R>(self, mut f: impl FnMut(T) -> R) -> Vector2<R> {
+        Vector2 {
+            x: f(self.x),
+            y: f(self.y),
+        }
+    }
+}
+
+impl<T: ToPrimitive + Copy> Vector2<T> {
+    /// Converts component-wise to another numeric type, returning `None` if either
+    /// component doesn't fit in `U`.
+    pub fn cast<U: NumCast>(self) -> Option<Vector2<U>> {
+        Some(Vector2 {
+            x: U::from(self.x)?,
+            y: U::from(self.y)?,
+        })
+    }
+}
+
+impl<T: std::ops::Mul<Output = T> + std::ops::Add<Output = T> + Copy> Vector2<T> {
+	pub fn dot<U: Into<Vector2<T>>>(&self, other: U) -> T {
+		let other: Vector2<T> = other.into();
+		self.x * other.x + self.y * other.y
+	}
+}
+
+impl<T: std::ops::Mul<Output = T> + std::ops::Sub<Output = T> + Copy> Vector2<T> {
+	/// Note: for unsigned `T` (e.g. `UVec2`) the intermediate subtraction can
+	/// underflow and panic in debug builds if the cross product is negative;
+	/// prefer a signed or float `T` unless the sign is known ahead of time.
+	pub fn cross<U: Into<Vector2<T>>>(&self, other: U) -> T {
+		let other: Vector2<T> = other.into();
+		self.x * other.y - self.y * other.x
+	}
+}
+
+impl<T: std::ops::AddAssign + Copy> Vector2<T> {
+	pub fn add<U: Into<Vector2<T>>>(&mut self, rhs: U) -> &mut Self {
+		let rhs: Vector2<T> = rhs.into();
 		self.x += rhs.x;
 		self.y += rhs.y;
 		self
 	}
-	
-	pub fn sub<U: Into<Vec2>>(&mut self, rhs: U) -> &mut Self {
-		let rhs: Vec2 = rhs.into();
+}
+
+impl<T: std::ops::SubAssign + Copy> Vector2<T> {
+	pub fn sub<U: Into<Vector2<T>>>(&mut self, rhs: U) -> &mut Self {
+		let rhs: Vector2<T> = rhs.into();
 		self.x -= rhs.x;
 		self.y -= rhs.y;
 		self
 	}
-	
-	pub fn dot<U: Into<Vec2>>(&self, other: U) -> f32 {
-		let other: Vec2 = other.into();
-		self.x * other.x + self.y * other.y
-	}
-	
-	pub fn cross<U: Into<Vec2>>(&self, other: U) -> f32 {
-		let other: Vec2 = other.into();
-		self.x * other.y - self.y * other.x
-	}
-    
-    pub fn mag(&self) -> f32 {
-        (self.x.powf(2.0) + self.y.powf(2.0)).sqrt()
+}
+
+impl<T: Float + std::ops::AddAssign + std::ops::SubAssign> Vector2<T> {
+    /// Builds a vector from a magnitude and an angle in radians.
+    pub fn from_polar_rad(mag: T, angle_rad: T) -> Self {
+        Self {
+            x: mag * angle_rad.cos(),
+            y: mag * angle_rad.sin(),
+        }
+    }
+
+    /// Builds a vector from a magnitude and an angle in degrees.
+    pub fn from_polar_deg(mag: T, angle_deg: T) -> Self {
+        Self::from_polar_rad(mag, angle_deg.to_radians())
     }
 
-    pub fn dir(&self) -> f32 {
+    /// Decomposes the vector into `(mag, angle_rad)`, the inverse of `from_polar_rad`.
+    pub fn to_polar(&self) -> (T, T) {
+        (self.mag(), self.dir())
+    }
+
+    pub fn mag(&self) -> T {
+        (self.x.powi(2) + self.y.powi(2)).sqrt()
+    }
+
+    pub fn dir(&self) -> T {
 		if self.x.is_zero() && self.y.is_zero() {
-			f32::zero()
+			T::zero()
 		}
 		else {
 			self.y.atan2(self.x)
@@ -51,43 +131,92 @@ impl Vec2 {
 	pub fn normalize(&mut self) -> &mut Self {
         let mag = self.mag();
         if !mag.is_zero() {
-            self.scale(1.0/mag);
+            self.scale(T::one() / mag);
         }
         self
     }
 
-	pub fn scale(&mut self, value: f32) -> &mut Self {
-        self.x *= value;
-        self.y *= value;
+	pub fn scale(&mut self, value: T) -> &mut Self {
+        self.x = self.x * value;
+        self.y = self.y * value;
         self
     }
 
-    pub fn set_direction(&mut self, rad: f32) -> &mut Self {
+    pub fn set_direction(&mut self, rad: T) -> &mut Self {
         let mag = self.mag();
-        if mag != 0.0 {
+        if !mag.is_zero() {
             self.x = rad.cos() * mag;
             self.y = rad.sin() * mag;
         }
         self
     }
 
-    pub fn rotate(&mut self, rad: f32) -> &mut Self {
+    pub fn rotate(&mut self, rad: T) -> &mut Self {
         self.set_direction(rad + self.dir());
         self
     }
 
-    pub fn rotate_over<U: Into<Vec2>>(&mut self, origin: U, rad: f32) -> &mut Self {
-		let origin: Vec2 = origin.into();
+    pub fn rotate_over<U: Into<Vector2<T>>>(&mut self, origin: U, rad: T) -> &mut Self {
+		let origin: Vector2<T> = origin.into();
         self.sub(origin);
         self.rotate(rad);
         self.add(origin);
         self
     }
-	
-    pub fn point_towards<U: Into<Vec2>>(&mut self, target: U) -> &mut Self {
-		let target: Vec2 = target.into();
+
+    pub fn point_towards<U: Into<Vector2<T>>>(&mut self, target: U) -> &mut Self {
+		let target: Vector2<T> = target.into();
         self.set_direction((target - *self).dir())
     }
+
+    /// Linearly interpolates between `self` and `other` by `t`, where `t = 0`
+    /// yields `self` and `t = 1` yields `other`.
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        self + (other - self) * t
+    }
+
+    /// Euclidean distance between two points.
+    pub fn distance(self, other: Self) -> T {
+        (self - other).mag()
+    }
+
+    /// Squared Euclidean distance, avoiding the `sqrt` in `distance`.
+    pub fn distance_sq(self, other: Self) -> T {
+        let delta = self - other;
+        delta.x * delta.x + delta.y * delta.y
+    }
+
+    /// Projects `self` onto `axis`, returning the component of `self` parallel to it.
+    pub fn project_onto(self, axis: Self) -> Self {
+        axis * (self.dot(axis) / axis.dot(axis))
+    }
+
+    /// Returns the component of `self` perpendicular to `axis`.
+    pub fn reject_from(self, axis: Self) -> Self {
+        self - self.project_onto(axis)
+    }
+
+    /// Reflects `self` off a surface with the given unit `normal`.
+    pub fn reflect(self, normal: Self) -> Self {
+        let two = T::one() + T::one();
+        self - normal * (two * self.dot(normal))
+    }
+
+    /// Signed angle in `(-π, π]` from `self` to `other`.
+    pub fn angle_between(self, other: Self) -> T {
+        self.cross(other).atan2(self.dot(other))
+    }
+
+    /// Scales `self` down so its magnitude does not exceed `max`, leaving it
+    /// unchanged if it is already shorter.
+    pub fn clamp_magnitude(self, max: T) -> Self {
+        let mag = self.mag();
+        if mag > max {
+            self * (max / mag)
+        } else {
+            self
+        }
+    }
 }
 
 
@@ -107,124 +236,299 @@ impl PartialEq<(f32, f32)> for Vec2 {
     }
 }
 
+impl PartialEq<IVec2> for IVec2 {
+    fn eq(&self, other: &IVec2) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl PartialEq<UVec2> for UVec2 {
+    fn eq(&self, other: &UVec2) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
 
-impl Add<Vec2> for Vec2 {
-    type Output = Vec2;
+impl<T: Add<Output = T>> Add<Vector2<T>> for Vector2<T> {
+    type Output = Vector2<T>;
 
-    fn add(self, rhs: Vec2) -> Vec2 {
-        Vec2 {
+    fn add(self, rhs: Vector2<T>) -> Vector2<T> {
+        Vector2 {
             x: self.x + rhs.x,
             y: self.y + rhs.y,
         }
     }
 }
 
-impl Sub<Vec2> for Vec2 {
-    type Output = Vec2;
+impl<T: Sub<Output = T>> Sub<Vector2<T>> for Vector2<T> {
+    type Output = Vector2<T>;
 
-    fn sub(self, rhs: Vec2) -> Vec2 {
-        Vec2 {
+    fn sub(self, rhs: Vector2<T>) -> Vector2<T> {
+        Vector2 {
             x: self.x - rhs.x,
             y: self.y - rhs.y,
         }
     }
 }
 
-impl AddAssign<Vec2> for Vec2 {
-    fn add_assign(&mut self, rhs: Vec2) {
+impl<T: AddAssign> AddAssign<Vector2<T>> for Vector2<T> {
+    fn add_assign(&mut self, rhs: Vector2<T>) {
         self.x += rhs.x;
         self.y += rhs.y;
     }
 }
 
-impl SubAssign<Vec2> for Vec2 {
-    fn sub_assign(&mut self, rhs: Vec2) {
+impl<T: SubAssign> SubAssign<Vector2<T>> for Vector2<T> {
+    fn sub_assign(&mut self, rhs: Vector2<T>) {
         self.x -= rhs.x;
         self.y -= rhs.y;
     }
 }
 
-impl Add<(f32, f32)> for Vec2 {
-    type Output = Vec2;
+impl<T: Add<Output = T>> Add<(T, T)> for Vector2<T> {
+    type Output = Vector2<T>;
 
-    fn add(self, rhs: (f32, f32)) -> Vec2 {
-        Vec2 {
+    fn add(self, rhs: (T, T)) -> Vector2<T> {
+        Vector2 {
             x: self.x + rhs.0,
             y: self.y + rhs.1,
         }
     }
 }
 
-impl Sub<(f32, f32)> for Vec2 {
-    type Output = Vec2;
+impl<T: Sub<Output = T>> Sub<(T, T)> for Vector2<T> {
+    type Output = Vector2<T>;
 
-    fn sub(self, rhs: (f32, f32)) -> Vec2 {
-        Vec2 {
+    fn sub(self, rhs: (T, T)) -> Vector2<T> {
+        Vector2 {
             x: self.x - rhs.0,
             y: self.y - rhs.1,
         }
     }
 }
 
-impl AddAssign<(f32, f32)> for Vec2 {
-    fn add_assign(&mut self, rhs: (f32, f32)) {
+impl<T: AddAssign> AddAssign<(T, T)> for Vector2<T> {
+    fn add_assign(&mut self, rhs: (T, T)) {
         self.x += rhs.0;
         self.y += rhs.1;
     }
 }
 
-impl SubAssign<(f32, f32)> for Vec2 {
-    fn sub_assign(&mut self, rhs: (f32, f32)) {
+impl<T: SubAssign> SubAssign<(T, T)> for Vector2<T> {
+    fn sub_assign(&mut self, rhs: (T, T)) {
         self.x -= rhs.0;
         self.y -= rhs.1;
     }
 }
 
-impl Add<Vec2> for (f32, f32) {
-    type Output = Vec2;
+impl<T: Add<Output = T>> Add<Vector2<T>> for (T, T) {
+    type Output = Vector2<T>;
 
-    fn add(self, rhs: Vec2) -> Vec2 {
-        Vec2 {
+    fn add(self, rhs: Vector2<T>) -> Vector2<T> {
+        Vector2 {
             x: self.0 + rhs.x,
             y: self.1 + rhs.y,
         }
     }
 }
 
-impl Sub<Vec2> for (f32, f32) {
-    type Output = Vec2;
+impl<T: Sub<Output = T>> Sub<Vector2<T>> for (T, T) {
+    type Output = Vector2<T>;
 
-    fn sub(self, rhs: Vec2) -> Vec2 {
-        Vec2 {
+    fn sub(self, rhs: Vector2<T>) -> Vector2<T> {
+        Vector2 {
             x: self.0 - rhs.x,
             y: self.1 - rhs.y,
         }
     }
 }
 
-impl AddAssign<Vec2> for (f32, f32) {
-    fn add_assign(&mut self, rhs: Vec2) {
+impl<T: AddAssign> AddAssign<Vector2<T>> for (T, T) {
+    fn add_assign(&mut self, rhs: Vector2<T>) {
         self.0 += rhs.x;
         self.1 += rhs.y;
     }
 }
 
-impl SubAssign<Vec2> for (f32, f32) {
-    fn sub_assign(&mut self, rhs: Vec2) {
+impl<T: SubAssign> SubAssign<Vector2<T>> for (T, T) {
+    fn sub_assign(&mut self, rhs: Vector2<T>) {
         self.0 -= rhs.x;
         self.1 -= rhs.y;
     }
 }
 
-impl From<(f32, f32)> for Vec2 {
-	fn from(input: (f32, f32)) -> Self {
-		Vec2 {
+use std::ops::{Div, DivAssign, Mul, MulAssign, Neg};
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn mul(self, rhs: T) -> Vector2<T> {
+        Vector2 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: Div<Output = T> + Copy> Div<T> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn div(self, rhs: T) -> Vector2<T> {
+        Vector2 {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+impl<T: MulAssign + Copy> MulAssign<T> for Vector2<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl<T: DivAssign + Copy> DivAssign<T> for Vector2<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
+
+impl<T: Mul<Output = T>> Mul<Vector2<T>> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn mul(self, rhs: Vector2<T>) -> Vector2<T> {
+        Vector2 {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
+        }
+    }
+}
+
+impl<T: Div<Output = T>> Div<Vector2<T>> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn div(self, rhs: Vector2<T>) -> Vector2<T> {
+        Vector2 {
+            x: self.x / rhs.x,
+            y: self.y / rhs.y,
+        }
+    }
+}
+
+impl<T: MulAssign> MulAssign<Vector2<T>> for Vector2<T> {
+    fn mul_assign(&mut self, rhs: Vector2<T>) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+    }
+}
+
+impl<T: DivAssign> DivAssign<Vector2<T>> for Vector2<T> {
+    fn div_assign(&mut self, rhs: Vector2<T>) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+    }
+}
+
+impl<T: Mul<Output = T>> Mul<(T, T)> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn mul(self, rhs: (T, T)) -> Vector2<T> {
+        Vector2 {
+            x: self.x * rhs.0,
+            y: self.y * rhs.1,
+        }
+    }
+}
+
+impl<T: Div<Output = T>> Div<(T, T)> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn div(self, rhs: (T, T)) -> Vector2<T> {
+        Vector2 {
+            x: self.x / rhs.0,
+            y: self.y / rhs.1,
+        }
+    }
+}
+
+impl<T: MulAssign> MulAssign<(T, T)> for Vector2<T> {
+    fn mul_assign(&mut self, rhs: (T, T)) {
+        self.x *= rhs.0;
+        self.y *= rhs.1;
+    }
+}
+
+impl<T: DivAssign> DivAssign<(T, T)> for Vector2<T> {
+    fn div_assign(&mut self, rhs: (T, T)) {
+        self.x /= rhs.0;
+        self.y /= rhs.1;
+    }
+}
+
+impl<T: Mul<Output = T>> Mul<Vector2<T>> for (T, T) {
+    type Output = Vector2<T>;
+
+    fn mul(self, rhs: Vector2<T>) -> Vector2<T> {
+        Vector2 {
+            x: self.0 * rhs.x,
+            y: self.1 * rhs.y,
+        }
+    }
+}
+
+impl<T: Div<Output = T>> Div<Vector2<T>> for (T, T) {
+    type Output = Vector2<T>;
+
+    fn div(self, rhs: Vector2<T>) -> Vector2<T> {
+        Vector2 {
+            x: self.0 / rhs.x,
+            y: self.1 / rhs.y,
+        }
+    }
+}
+
+impl<T: MulAssign> MulAssign<Vector2<T>> for (T, T) {
+    fn mul_assign(&mut self, rhs: Vector2<T>) {
+        self.0 *= rhs.x;
+        self.1 *= rhs.y;
+    }
+}
+
+impl<T: DivAssign> DivAssign<Vector2<T>> for (T, T) {
+    fn div_assign(&mut self, rhs: Vector2<T>) {
+        self.0 /= rhs.x;
+        self.1 /= rhs.y;
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn neg(self) -> Vector2<T> {
+        Vector2 {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T> From<(T, T)> for Vector2<T> {
+	fn from(input: (T, T)) -> Self {
+		Vector2 {
 			x: input.0,
 			y: input.1
 		}
 	}
 }
 
+impl<T> From<Vector2<T>> for (T, T) {
+	fn from(input: Vector2<T>) -> Self {
+		(input.x, input.y)
+	}
+}
+
 
 impl std::fmt::Display for Vec2 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -282,6 +586,104 @@ mod tests {
         assert_eq!(vec, Vec2::new(3.0, 4.0));
     }
 
+    #[test]
+    fn test_scalar_mul() {
+        let vec = Vec2::new(1.0, 2.0);
+        assert_eq!(vec * 2.0, Vec2::new(2.0, 4.0));
+    }
+
+    #[test]
+    fn test_scalar_div() {
+        let vec = Vec2::new(2.0, 4.0);
+        assert_eq!(vec / 2.0, Vec2::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_scalar_mul_assign() {
+        let mut vec = Vec2::new(1.0, 2.0);
+        vec *= 2.0;
+        assert_eq!(vec, Vec2::new(2.0, 4.0));
+    }
+
+    #[test]
+    fn test_scalar_div_assign() {
+        let mut vec = Vec2::new(2.0, 4.0);
+        vec /= 2.0;
+        assert_eq!(vec, Vec2::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_componentwise_mul() {
+        let v1 = Vec2::new(2.0, 3.0);
+        let v2 = Vec2::new(4.0, 5.0);
+        assert_eq!(v1 * v2, Vec2::new(8.0, 15.0));
+    }
+
+    #[test]
+    fn test_componentwise_div() {
+        let v1 = Vec2::new(8.0, 15.0);
+        let v2 = Vec2::new(4.0, 5.0);
+        assert_eq!(v1 / v2, Vec2::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn test_negation() {
+        let vec = Vec2::new(1.0, -2.0);
+        assert_eq!(-vec, Vec2::new(-1.0, 2.0));
+    }
+
+    #[test]
+    fn test_mul_with_tuple() {
+        let vec = Vec2::new(2.0, 3.0);
+        assert_eq!(vec * (4.0, 5.0), Vec2::new(8.0, 15.0));
+    }
+
+    #[test]
+    fn test_div_with_tuple() {
+        let vec = Vec2::new(8.0, 15.0);
+        assert_eq!(vec / (4.0, 5.0), Vec2::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn test_mul_assign_with_tuple() {
+        let mut vec = Vec2::new(2.0, 3.0);
+        vec *= (4.0, 5.0);
+        assert_eq!(vec, Vec2::new(8.0, 15.0));
+    }
+
+    #[test]
+    fn test_div_assign_with_tuple() {
+        let mut vec = Vec2::new(8.0, 15.0);
+        vec /= (4.0, 5.0);
+        assert_eq!(vec, Vec2::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn test_tuple_mul_with_vec() {
+        let vec = Vec2::new(4.0, 5.0);
+        assert_eq!((2.0, 3.0) * vec, Vec2::new(8.0, 15.0));
+    }
+
+    #[test]
+    fn test_tuple_div_with_vec() {
+        let vec = Vec2::new(4.0, 5.0);
+        assert_eq!((8.0, 15.0) / vec, Vec2::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn test_tuple_mul_assign_with_vec() {
+        let mut tup = (2.0, 3.0);
+        tup *= Vec2::new(4.0, 5.0);
+        assert_eq!(tup, (8.0, 15.0));
+    }
+
+    #[test]
+    fn test_tuple_div_assign_with_vec() {
+        let mut tup = (8.0, 15.0);
+        tup /= Vec2::new(4.0, 5.0);
+        assert_eq!(tup, (2.0, 3.0));
+    }
+
     #[test]
     fn test_dot_product() {
         let v1 = Vec2::new(1.0, 2.0);
@@ -328,7 +730,8 @@ mod tests {
     fn test_set_direction() {
         let mut vec = Vec2::new(3.0, 4.0);
         vec.set_direction(std::f32::consts::PI);
-        assert_eq!(vec, (-5.0, 0.0));
+        assert!((vec.x - -5.0).abs() < 1e-6);
+        assert!((vec.y - 0.0).abs() < 1e-6);
     }
 
     #[test]
@@ -348,6 +751,15 @@ mod tests {
         assert!((vec.y - 1.0).abs() < 1e-6);
     }
 
+    #[test]
+    fn test_rotate_over_nonzero_origin() {
+        let mut vec = Vec2::new(2.0, 1.0);
+        let origin = Vec2::new(1.0, 1.0);
+        vec.rotate_over(origin, std::f32::consts::FRAC_PI_2);
+        assert!((vec.x - 1.0).abs() < 1e-6);
+        assert!((vec.y - 2.0).abs() < 1e-6);
+    }
+
     #[test]
     fn test_point_towards() {
         let mut vec = Vec2::new(1.0, 0.0);
@@ -361,4 +773,155 @@ mod tests {
         let vec: Vec2 = Default::default();
         assert_eq!(vec, Vec2::new(0.0, 0.0));
     }
+
+    #[test]
+    fn test_ivec2_exact_equality() {
+        let a = IVec2::new(2, -3);
+        let b = IVec2::new(2, -3);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_uvec2_addition() {
+        let a = UVec2::new(2, 3);
+        let b = UVec2::new(4, 5);
+        assert_eq!(a + b, UVec2::new(6, 8));
+    }
+
+    #[test]
+    fn test_map() {
+        let vec = Vec2::new(1.5, 2.5);
+        let doubled = vec.map(|v| v * 2.0);
+        assert_eq!(doubled, Vec2::new(3.0, 5.0));
+    }
+
+    #[test]
+    fn test_cast_float_to_int() {
+        let vec = Vec2::new(3.0, 4.0);
+        let ivec: IVec2 = vec.cast().unwrap();
+        assert_eq!(ivec, IVec2::new(3, 4));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let a = Vec2::new(0.0, 0.0);
+        let b = Vec2::new(10.0, 10.0);
+        assert_eq!(a.lerp(b, 0.5), Vec2::new(5.0, 5.0));
+    }
+
+    #[test]
+    fn test_distance() {
+        let a = Vec2::new(0.0, 0.0);
+        let b = Vec2::new(3.0, 4.0);
+        assert!((a.distance(b) - 5.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_distance_sq() {
+        let a = Vec2::new(0.0, 0.0);
+        let b = Vec2::new(3.0, 4.0);
+        assert_eq!(a.distance_sq(b), 25.0);
+    }
+
+    #[test]
+    fn test_project_onto() {
+        let vec = Vec2::new(3.0, 4.0);
+        let axis = Vec2::new(1.0, 0.0);
+        assert_eq!(vec.project_onto(axis), Vec2::new(3.0, 0.0));
+    }
+
+    #[test]
+    fn test_reject_from() {
+        let vec = Vec2::new(3.0, 4.0);
+        let axis = Vec2::new(1.0, 0.0);
+        assert_eq!(vec.reject_from(axis), Vec2::new(0.0, 4.0));
+    }
+
+    #[test]
+    fn test_reflect() {
+        let vec = Vec2::new(1.0, -1.0);
+        let normal = Vec2::new(0.0, 1.0);
+        assert_eq!(vec.reflect(normal), Vec2::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_angle_between() {
+        let a = Vec2::new(1.0, 0.0);
+        let b = Vec2::new(0.0, 1.0);
+        assert!((a.angle_between(b) - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_clamp_magnitude_shrinks_when_over() {
+        let vec = Vec2::new(3.0, 4.0);
+        let clamped = vec.clamp_magnitude(2.0);
+        assert!((clamped.mag() - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_clamp_magnitude_leaves_under_unchanged() {
+        let vec = Vec2::new(1.0, 0.0);
+        assert_eq!(vec.clamp_magnitude(2.0), vec);
+    }
+
+    #[test]
+    fn test_from_polar_rad() {
+        let vec = Vec2::from_polar_rad(5.0, 0.0);
+        assert_eq!(vec, Vec2::new(5.0, 0.0));
+    }
+
+    #[test]
+    fn test_from_polar_deg() {
+        let vec = Vec2::from_polar_deg(5.0, 90.0);
+        assert!((vec.x - 0.0).abs() < 1e-5);
+        assert!((vec.y - 5.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_to_polar() {
+        let vec = Vec2::new(3.0, 4.0);
+        let (mag, angle) = vec.to_polar();
+        assert!((mag - 5.0).abs() < 1e-6);
+        assert!((angle - vec.dir()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_to_polar_zero() {
+        let vec = Vec2::new(0.0, 0.0);
+        assert_eq!(vec.to_polar(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_cast_int_to_float() {
+        let vec = IVec2::new(3, 4);
+        let fvec: Vec2 = vec.cast().unwrap();
+        assert_eq!(fvec, Vec2::new(3.0, 4.0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_wire_format_is_two_element_array() {
+        let vec = Vec2::new(1.0, 2.0);
+        let json = serde_json::to_string(&vec).unwrap();
+        assert_eq!(json, "[1.0,2.0]");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_round_trip() {
+        let vec = Vec2::new(1.0, 2.0);
+        let json = serde_json::to_string(&vec).unwrap();
+        let back: Vec2 = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, vec);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_ivec2_round_trip() {
+        let vec = IVec2::new(3, -4);
+        let json = serde_json::to_string(&vec).unwrap();
+        assert_eq!(json, "[3,-4]");
+        let back: IVec2 = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, vec);
+    }
 }